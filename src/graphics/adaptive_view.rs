@@ -0,0 +1,198 @@
+use graphics::{FloatRect, View, ViewBox};
+use system::{Vector2f, Vector2u};
+
+/// A view controller that reacts to changes in the size of the render
+/// target it is applied to.
+///
+/// Implementors recompute the wrapped [`View`]'s size and/or viewport so
+/// that the displayed content keeps whatever behaviour it promises (fixed
+/// aspect ratio, letterboxing, pixel-perfect mapping, ...) when the window
+/// is resized. Feed the new framebuffer size to `on_resize` whenever a
+/// `Resized` event is received, then draw with `view()`.
+pub trait AdaptiveView {
+    /// Recompute the view for a new framebuffer size
+    fn on_resize(&mut self, framebuffer: Vector2u);
+    /// Borrow the underlying view
+    fn view(&self) -> &View;
+}
+
+fn centered_view(world_size: Vector2f) -> ViewBox {
+    View::new(Vector2f::new(world_size.x / 2.0, world_size.y / 2.0), world_size)
+}
+
+/// Keeps the world size fixed and stretches it to cover the whole
+/// viewport, so the aspect ratio follows the window's.
+#[derive(Debug)]
+pub struct StretchView {
+    view: ViewBox,
+}
+
+impl StretchView {
+    /// Create a view displaying `world_size` world units, stretched to
+    /// fill the whole target regardless of its aspect ratio
+    pub fn new(world_size: Vector2f) -> Self {
+        StretchView { view: centered_view(world_size) }
+    }
+}
+
+impl AdaptiveView for StretchView {
+    fn on_resize(&mut self, _framebuffer: Vector2u) {
+        // Size and viewport never change: the content is simply deformed
+        // to fit whatever shape the target has.
+    }
+    fn view(&self) -> &View {
+        &self.view
+    }
+}
+
+/// Preserves the world's aspect ratio and adds black bars (letterboxing)
+/// rather than deforming the content.
+#[derive(Debug)]
+pub struct FitView {
+    view: ViewBox,
+    world_size: Vector2f,
+}
+
+impl FitView {
+    /// Create a view displaying `world_size` world units, letterboxed to
+    /// preserve its aspect ratio
+    pub fn new(world_size: Vector2f) -> Self {
+        FitView { view: centered_view(world_size), world_size }
+    }
+}
+
+impl AdaptiveView for FitView {
+    fn on_resize(&mut self, framebuffer: Vector2u) {
+        let fb = Vector2f::new(framebuffer.x as f32, framebuffer.y as f32);
+        let scale = (fb.x / self.world_size.x).min(fb.y / self.world_size.y);
+        let pixel_size = Vector2f::new(self.world_size.x * scale, self.world_size.y * scale);
+        self.view.set_viewport(&FloatRect::new(
+            (fb.x - pixel_size.x) / 2.0 / fb.x,
+            (fb.y - pixel_size.y) / 2.0 / fb.y,
+            pixel_size.x / fb.x,
+            pixel_size.y / fb.y,
+        ));
+    }
+    fn view(&self) -> &View {
+        &self.view
+    }
+}
+
+/// Preserves the world's aspect ratio and fills the whole viewport,
+/// cropping whatever overflows instead of adding black bars.
+#[derive(Debug)]
+pub struct FillView {
+    view: ViewBox,
+    world_size: Vector2f,
+}
+
+impl FillView {
+    /// Create a view displaying `world_size` world units, cropped to fill
+    /// the whole target
+    pub fn new(world_size: Vector2f) -> Self {
+        FillView { view: centered_view(world_size), world_size }
+    }
+}
+
+impl AdaptiveView for FillView {
+    fn on_resize(&mut self, framebuffer: Vector2u) {
+        let fb = Vector2f::new(framebuffer.x as f32, framebuffer.y as f32);
+        let scale = (fb.x / self.world_size.x).max(fb.y / self.world_size.y);
+        let pixel_size = Vector2f::new(self.world_size.x * scale, self.world_size.y * scale);
+        self.view.set_viewport(&FloatRect::new(
+            (fb.x - pixel_size.x) / 2.0 / fb.x,
+            (fb.y - pixel_size.y) / 2.0 / fb.y,
+            pixel_size.x / fb.x,
+            pixel_size.y / fb.y,
+        ));
+    }
+    fn view(&self) -> &View {
+        &self.view
+    }
+}
+
+/// Keeps the viewport full but extends the view on whichever dimension
+/// the window grows in, revealing more of the world instead of cropping
+/// or letterboxing it.
+#[derive(Debug)]
+pub struct ExtendView {
+    view: ViewBox,
+    world_size: Vector2f,
+}
+
+impl ExtendView {
+    /// Create a view displaying at least `world_size` world units,
+    /// extended on resize to reveal more of the world
+    pub fn new(world_size: Vector2f) -> Self {
+        ExtendView { view: centered_view(world_size), world_size }
+    }
+}
+
+impl AdaptiveView for ExtendView {
+    fn on_resize(&mut self, framebuffer: Vector2u) {
+        let fb = Vector2f::new(framebuffer.x as f32, framebuffer.y as f32);
+        let scale = (fb.x / self.world_size.x).min(fb.y / self.world_size.y);
+        self.view.set_size(Vector2f::new(fb.x / scale, fb.y / scale));
+        self.view.set_viewport(&FloatRect::new(0.0, 0.0, 1.0, 1.0));
+    }
+    fn view(&self) -> &View {
+        &self.view
+    }
+}
+
+/// Maps one world unit to one screen pixel, with a top-left origin, by
+/// matching the view's size to the framebuffer's.
+#[derive(Debug)]
+pub struct ScreenView {
+    view: ViewBox,
+}
+
+impl ScreenView {
+    /// Create a view matching `framebuffer`'s size, one world unit per pixel
+    pub fn new(framebuffer: Vector2u) -> Self {
+        let size = Vector2f::new(framebuffer.x as f32, framebuffer.y as f32);
+        ScreenView { view: centered_view(size) }
+    }
+}
+
+impl AdaptiveView for ScreenView {
+    fn on_resize(&mut self, framebuffer: Vector2u) {
+        let size = Vector2f::new(framebuffer.x as f32, framebuffer.y as f32);
+        self.view.set_size(size);
+        self.view.set_center(Vector2f::new(size.x / 2.0, size.y / 2.0));
+    }
+    fn view(&self) -> &View {
+        &self.view
+    }
+}
+
+/// Keeps a fixed world size centered in the target, cropping it when the
+/// window is smaller than the world and bordering it when larger.
+#[derive(Debug)]
+pub struct LockedView {
+    view: ViewBox,
+    world_size: Vector2f,
+}
+
+impl LockedView {
+    /// Create a view locked to `world_size` world units
+    pub fn new(world_size: Vector2f) -> Self {
+        LockedView { view: centered_view(world_size), world_size }
+    }
+}
+
+impl AdaptiveView for LockedView {
+    fn on_resize(&mut self, framebuffer: Vector2u) {
+        let width = self.world_size.x / framebuffer.x as f32;
+        let height = self.world_size.y / framebuffer.y as f32;
+        self.view.set_viewport(&FloatRect::new(
+            (1.0 - width) / 2.0,
+            (1.0 - height) / 2.0,
+            width,
+            height,
+        ));
+    }
+    fn view(&self) -> &View {
+        &self.view
+    }
+}
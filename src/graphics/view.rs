@@ -1,8 +1,10 @@
 use graphics::FloatRect;
+use graphics::Transform;
 use graphics::csfml_graphics_sys as ffi;
 use std::borrow::{Borrow, ToOwned};
+use std::f32::consts::PI;
 use std::ops::{Deref, DerefMut};
-use system::Vector2f;
+use system::{Vector2f, Vector2i, Vector2u};
 use std::fmt::{self, Debug};
 
 extern "C" {
@@ -151,6 +153,87 @@ impl View {
     pub fn reset(&mut self, rectangle: &FloatRect) {
         unsafe { ffi::sfView_reset(self.raw_mut(), rectangle.raw()) }
     }
+    /// Get the projection transform of the view
+    ///
+    /// This function is meant for internal use only, unless you
+    /// want to implement your own drawing logic relying directly
+    /// on this transform instead of going through a `RenderTarget`.
+    pub fn transform(&self) -> Transform {
+        let angle = self.rotation() * PI / 180.0;
+        let cos = angle.cos();
+        let sin = angle.sin();
+        let center = self.center();
+        let size = self.size();
+        let tx = -center.x * cos - center.y * sin + center.x;
+        let ty = center.x * sin - center.y * cos + center.y;
+        let a = 2.0 / size.x;
+        let b = -2.0 / size.y;
+        let c = -a * center.x;
+        let d = -b * center.y;
+        Transform::new(
+            a * cos,
+            a * sin,
+            a * tx + c,
+            -b * sin,
+            b * cos,
+            b * ty + d,
+            0.0,
+            0.0,
+            1.0,
+        )
+    }
+
+    /// Get the inverse of the projection transform of the view
+    ///
+    /// This function is meant for internal use only, unless you
+    /// want to implement your own drawing logic relying directly
+    /// on this transform instead of going through a `RenderTarget`.
+    pub fn inverse_transform(&self) -> Transform {
+        self.transform().inverse()
+    }
+
+    /// Convert a point from target coordinates to world coordinates
+    ///
+    /// This function finds the 2D position that matches the given pixel
+    /// of a render target, using this view for the conversion. It's the
+    /// `View` counterpart of `RenderTarget::map_pixel_to_coords`, for use
+    /// when you only have a target size rather than a `RenderTarget`.
+    ///
+    /// # Arguments
+    /// * pixel - Pixel to convert
+    /// * target_size - Size of the render target `self` would be applied to
+    pub fn map_pixel_to_coords(&self, pixel: Vector2i, target_size: Vector2u) -> Vector2f {
+        let viewport = self.viewport();
+        let target = Vector2f::new(target_size.x as f32, target_size.y as f32);
+        let normalized = Vector2f::new(
+            -1.0 + 2.0 * (pixel.x as f32 - viewport.left * target.x) / (viewport.width * target.x),
+            1.0 - 2.0 * (pixel.y as f32 - viewport.top * target.y) / (viewport.height * target.y),
+        );
+        self.inverse_transform().transform_point(normalized)
+    }
+
+    /// Convert a point from world coordinates to target coordinates
+    ///
+    /// This function finds the pixel of a render target that matches the
+    /// given 2D point, using this view for the conversion. It's the
+    /// `View` counterpart of `RenderTarget::map_coords_to_pixel`, for use
+    /// when you only have a target size rather than a `RenderTarget`.
+    ///
+    /// # Arguments
+    /// * point - Point to convert
+    /// * target_size - Size of the render target `self` would be applied to
+    pub fn map_coords_to_pixel(&self, point: Vector2f, target_size: Vector2u) -> Vector2i {
+        let normalized = self.transform().transform_point(point);
+        let viewport = self.viewport();
+        let target = Vector2f::new(target_size.x as f32, target_size.y as f32);
+        Vector2i::new(
+            ((normalized.x + 1.0) / 2.0 * viewport.width * target.x + viewport.left * target.x)
+                .round() as i32,
+            ((1.0 - normalized.y) / 2.0 * viewport.height * target.y + viewport.top * target.y)
+                .round() as i32,
+        )
+    }
+
     pub(super) fn raw(&self) -> *const ffi::sfView {
         let ptr: *const Self = self;
         ptr as _
@@ -161,6 +244,93 @@ impl View {
     }
 }
 
+impl View {
+    /// Start building a view with a fluent, one-expression API
+    ///
+    /// This is an alternative to `View::new`/`View::from_rect` for the
+    /// case where a rotation and/or a non-default viewport also need to
+    /// be set, e.g. when configuring a split-screen camera.
+    pub fn builder() -> ViewBuilder {
+        ViewBuilder::default()
+    }
+}
+
+/// Builder for constructing a fully-configured `ViewBox` in one expression
+///
+/// # Usage example
+/// ```ignore
+/// let view = View::builder()
+///     .center((400., 300.))
+///     .size((800., 600.))
+///     .rotation(15.)
+///     .viewport(&FloatRect::new(0., 0., 0.5, 1.))
+///     .build();
+/// ```
+#[derive(Debug, Default)]
+pub struct ViewBuilder {
+    center: Option<Vector2f>,
+    size: Option<Vector2f>,
+    rectangle: Option<FloatRect>,
+    rotation: Option<f32>,
+    viewport: Option<FloatRect>,
+}
+
+impl ViewBuilder {
+    /// Set the center of the view
+    pub fn center<C: Into<Vector2f>>(mut self, center: C) -> Self {
+        self.center = Some(center.into());
+        self
+    }
+
+    /// Set the size of the view
+    pub fn size<S: Into<Vector2f>>(mut self, size: S) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+
+    /// Initialize the view from a rectangle defining the zone to display
+    ///
+    /// If both `rectangle` and `center`/`size` are set, `center`/`size`
+    /// take precedence as they're applied after the rectangle.
+    pub fn rectangle(mut self, rectangle: &FloatRect) -> Self {
+        self.rectangle = Some(rectangle.clone());
+        self
+    }
+
+    /// Set the orientation of the view, in degrees
+    pub fn rotation(mut self, angle: f32) -> Self {
+        self.rotation = Some(angle);
+        self
+    }
+
+    /// Set the target viewport of the view
+    pub fn viewport(mut self, viewport: &FloatRect) -> Self {
+        self.viewport = Some(viewport.clone());
+        self
+    }
+
+    /// Build the configured view
+    pub fn build(self) -> ViewBox {
+        let mut view = match self.rectangle {
+            Some(rectangle) => View::from_rect(&rectangle),
+            None => ViewBox::default(),
+        };
+        if let Some(center) = self.center {
+            view.set_center(center);
+        }
+        if let Some(size) = self.size {
+            view.set_size(size);
+        }
+        if let Some(rotation) = self.rotation {
+            view.set_rotation(rotation);
+        }
+        if let Some(viewport) = self.viewport {
+            view.set_viewport(&viewport);
+        }
+        view
+    }
+}
+
 impl ToOwned for View {
     type Owned = ViewBox;
     fn to_owned(&self) -> Self::Owned {